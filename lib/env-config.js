@@ -27,6 +27,10 @@ const projectRoot = join(__dirname, "..");
  *                        Also derives `shutdownBudget`, the caller-side stop
  *                        watchdog that `safeStopServer` uses — bumping this
  *                        widens both sides automatically.
+ *   WS_PING_INTERVAL   — WebSocket heartbeat ping interval in ms (default: 30000)
+ *   WS_MAX_MISSED_PONGS — Consecutive missed pongs before a WebSocket is
+ *                        terminated (default: 2). Dead-peer detection time is
+ *                        roughly interval × missed pongs.
  *   HOME               — User home directory; used as the initial cwd for tmux sessions
  *   KATULONG_TRUST_PROXY_SECRET — Shared secret for trusted reverse proxy auth.
  *                        When set, requests with matching X-Katulong-Auth header
@@ -48,6 +52,42 @@ const drainTimeoutMs = parseInt(process.env.DRAIN_TIMEOUT || "30000", 10);
 // a weaker positivity check.
 export const SHUTDOWN_TAIL_SLACK_MS = 3000;
 
+// Largest delay setTimeout/setInterval honour. Anything above it triggers
+// a TimeoutOverflowWarning and is clamped to 1ms — the opposite of what a
+// large interval was meant to do.
+export const MAX_TIMER_MS = 2 ** 31 - 1;
+
+/**
+ * Read a positive integer env var, falling back to `fallback` when unset.
+ *
+ * parseInt alone is too lenient for timing knobs: "30s" parses to 30 (a
+ * 30ms ping), "0" makes setInterval fire every 1ms, and "two" gives NaN,
+ * which silently disables any `>= NaN` threshold check. Anything that isn't
+ * a plain positive integer no greater than `max` is rejected with a
+ * warning. The warning goes straight to stderr in log.js's line format —
+ * log.js imports this module, so importing it back would be circular.
+ *
+ * @param {string} name - Environment variable name
+ * @param {number} fallback - Default when unset or invalid
+ * @param {object} [opts]
+ * @param {number} [opts.max] - Upper bound, inclusive (default: MAX_SAFE_INTEGER).
+ *   Pass MAX_TIMER_MS for values fed to setTimeout/setInterval.
+ * @param {object} [opts.env] - Environment to read (default: process.env)
+ * @returns {number}
+ */
+export function positiveIntEnv(name, fallback, { max = Number.MAX_SAFE_INTEGER, env = process.env } = {}) {
+  const raw = env[name];
+  if (raw === undefined || raw === "") return fallback;
+  const value = /^\d+$/.test(raw.trim()) ? Number(raw.trim()) : NaN;
+  if (Number.isSafeInteger(value) && value > 0 && value <= max) return value;
+  process.stderr.write(JSON.stringify({
+    time: new Date().toISOString(), level: "warn",
+    msg: "Invalid environment value, using default",
+    meta: { name, value: raw, default: fallback, max },
+  }) + "\n");
+  return fallback;
+}
+
 const config = Object.freeze({
   // HTTP server
   port: parseInt(process.env.PORT || "3001", 10),
@@ -78,6 +118,11 @@ const config = Object.freeze({
   // to both sides automatically — there is no separate knob to forget.
   shutdownBudget: drainTimeoutMs + SHUTDOWN_TAIL_SLACK_MS,
 
+  // WebSocket heartbeat. Localhost wants fast dead-peer detection; flaky
+  // mobile links want more slack before a stalled socket is terminated.
+  wsPingInterval: positiveIntEnv("WS_PING_INTERVAL", 30000, { max: MAX_TIMER_MS }),
+  wsMaxMissedPongs: positiveIntEnv("WS_MAX_MISSED_PONGS", 2),
+
   // User home directory (used as initial cwd for PTY sessions)
   home: process.env.HOME || null,
 
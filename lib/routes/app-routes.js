@@ -175,7 +175,7 @@ export function createAppRoutes(ctx) {
     configManager, __dirname, DATA_DIR, APP_VERSION,
     getDraining, shortcutsPath,
     auth, csrf, topicBroker, getExternalUrl,
-    permissionStore, callOllama,
+    permissionStore, callOllama, getClientCount,
   } = ctx;
 
   // Tracks the async pane-scan started by each PreToolUse, keyed by the
@@ -235,6 +235,7 @@ export function createAppRoutes(ctx) {
       if (isAuthenticated(req)) {
         response.pid = process.pid;
         response.uptime = process.uptime();
+        response.clients = getClientCount?.() ?? 0;
         // Surface which LLM backend is currently in use (peer-bridge,
         // local-31b, local-cloud) so an operator can see at a glance
         // whether the cascade is hitting the bridge or has fallen back.
@@ -14,6 +14,7 @@ import { validateMessage } from "./websocket-validation.js";
 import { SessionName } from "./session-name.js";
 import { loadState } from "./auth.js";
 import { log } from "./log.js";
+import envConfig from "./env-config.js";
 import { createClientTransport } from "./client-transport.js";
 import { createWebRTCSignaling } from "./webrtc-signaling.js";
 import { driftLog } from "./drift-log.js";
@@ -24,9 +25,17 @@ import { driftLog } from "./drift-log.js";
  * @param {object} opts
  * @param {object} opts.bridge - Transport bridge
  * @param {object} opts.sessionManager - Session manager instance
+ * @param {number} [opts.pingIntervalMs] - Heartbeat ping interval (default: WS_PING_INTERVAL)
+ * @param {number} [opts.maxMissedPongs] - Missed pongs before terminate (default: WS_MAX_MISSED_PONGS)
  * @returns {object} Manager API
  */
-export function createWebSocketManager({ bridge, sessionManager, pluginWsHandlers = {} }) {
+export function createWebSocketManager({
+  bridge,
+  sessionManager,
+  pluginWsHandlers = {},
+  pingIntervalMs = envConfig.wsPingInterval,
+  maxMissedPongs = envConfig.wsMaxMissedPongs,
+}) {
   // clientId -> { transport, sessionToken, credentialId }
   // transport is a ClientTransport wrapping WS + optional DataChannel.
   // Note: session binding lives in the client-tracker (single source of truth).
@@ -240,9 +249,6 @@ export function createWebSocketManager({ bridge, sessionManager, pluginWsHandler
     }
   });
 
-  const PING_INTERVAL_MS = 30000;
-  const MAX_MISSED_PONGS = 2;
-
   /**
    * Handle a newly-upgraded WebSocket connection.
    *
@@ -271,7 +277,7 @@ export function createWebSocketManager({ bridge, sessionManager, pluginWsHandler
     let closed = false;
     log.debug("Client connected", { clientId });
 
-    // Heartbeat: ping every 30s, terminate after 2 missed pongs.
+    // Heartbeat: ping every pingIntervalMs, terminate after maxMissedPongs.
     // Always uses the underlying WebSocket (DataChannel has no ping/pong).
     let missedPongs = 0;
     ws.isAlive = true;
@@ -279,7 +285,7 @@ export function createWebSocketManager({ bridge, sessionManager, pluginWsHandler
     const pingTimer = setInterval(() => {
       if (!ws.isAlive) {
         missedPongs++;
-        if (missedPongs >= MAX_MISSED_PONGS) {
+        if (missedPongs >= maxMissedPongs) {
           log.warn("WebSocket terminated: missed pongs", { clientId, missedPongs });
           clearInterval(pingTimer);
           ws.terminate();
@@ -288,7 +294,7 @@ export function createWebSocketManager({ bridge, sessionManager, pluginWsHandler
       }
       ws.isAlive = false;
       if (ws.readyState === 1) ws.ping();
-    }, PING_INTERVAL_MS);
+    }, pingIntervalMs);
 
     // Serialize async message handling per client to prevent interleaving
     // (e.g., two rapid attach messages racing each other).
@@ -303,7 +309,16 @@ export function createWebSocketManager({ bridge, sessionManager, pluginWsHandler
 
     async function handleMessage(raw) {
       let msg;
-      try { msg = JSON.parse(raw.toString()); } catch { return; } // Malformed JSON — silently drop
+      try {
+        msg = JSON.parse(raw.toString());
+      } catch {
+        // Reply rather than drop: a client awaiting a response to a garbled
+        // frame would otherwise wait forever with no hint of what went wrong.
+        // Length only: frames carry keystrokes, so content could leak secrets.
+        log.debug("Malformed WebSocket message", { clientId, length: raw.length });
+        transport.send(JSON.stringify({ type: "error", message: "Malformed JSON" }));
+        return;
+      }
 
       const validation = validateMessage(msg);
       if (!validation.valid) {
@@ -374,6 +374,7 @@ const routes = [
     topicBroker,
     permissionStore,
     callOllama,
+    getClientCount: () => wsClients.size,
     getExternalUrl: () => configManager.getPublicUrl(),
   }),
   ...createClaudeFeedRoutes({
@@ -1,6 +1,6 @@
 import { describe, it } from "node:test";
 import assert from "node:assert/strict";
-import envConfig from "../lib/env-config.js";
+import envConfig, { positiveIntEnv, MAX_TIMER_MS } from "../lib/env-config.js";
 
 describe("env-config", () => {
   it("exports a frozen object", () => {
@@ -11,6 +11,7 @@ describe("env-config", () => {
     const expectedKeys = [
       "port", "bindHost", "dataDir",
       "shell", "nodeEnv", "logLevel", "drainTimeout", "home",
+      "wsPingInterval", "wsMaxMissedPongs",
     ];
     for (const key of expectedKeys) {
       assert.ok(key in envConfig, `config must have key: ${key}`);
@@ -67,6 +68,46 @@ describe("env-config", () => {
     }
   });
 
+  it("defaults: WebSocket heartbeat is 30000ms with 2 missed pongs", () => {
+    if (!process.env.WS_PING_INTERVAL) {
+      assert.equal(envConfig.wsPingInterval, 30000);
+    }
+    if (!process.env.WS_MAX_MISSED_PONGS) {
+      assert.equal(envConfig.wsMaxMissedPongs, 2);
+    }
+  });
+
+  describe("positiveIntEnv", () => {
+    it("returns the parsed value for a positive integer", () => {
+      assert.equal(positiveIntEnv("X", 30000, { env: { X: "5000" } }), 5000);
+    });
+
+    it("returns the default when unset or empty", () => {
+      assert.equal(positiveIntEnv("X", 30000, { env: {} }), 30000);
+      assert.equal(positiveIntEnv("X", 30000, { env: { X: "" } }), 30000);
+    });
+
+    it("falls back to the default for invalid values", (t) => {
+      t.mock.method(process.stderr, "write", () => true);
+      for (const bad of ["30s", "0", "-5", "two", "1.5", "NaN"]) {
+        assert.equal(positiveIntEnv("X", 2, { env: { X: bad } }), 2, `"${bad}" must fall back`);
+      }
+      // Above the timer ceiling setInterval clamps to 1ms — a ping storm.
+      assert.equal(
+        positiveIntEnv("X", 30000, { max: MAX_TIMER_MS, env: { X: "3000000000" } }),
+        30000,
+      );
+      assert.equal(process.stderr.write.mock.callCount(), 7, "warns once per invalid value");
+    });
+
+    it("accepts a value exactly at max", () => {
+      assert.equal(
+        positiveIntEnv("X", 30000, { max: MAX_TIMER_MS, env: { X: String(MAX_TIMER_MS) } }),
+        MAX_TIMER_MS,
+      );
+    });
+  });
+
   it("config cannot be mutated (frozen)", () => {
     assert.throws(() => {
       "use strict";
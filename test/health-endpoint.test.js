@@ -1,7 +1,38 @@
-import { describe, it } from "node:test";
+import { describe, it, mock } from "node:test";
 import assert from "node:assert/strict";
 import { isPublicPath } from "../lib/http-util.js";
 
+// Mock auth before importing app-routes (it pulls in @simplewebauthn/server)
+const authModuleUrl = new URL("../lib/auth.js", import.meta.url).href;
+
+mock.module(authModuleUrl, {
+  namedExports: {
+    loadState: () => null,
+  },
+});
+
+const { createAppRoutes } = await import("../lib/routes/app-routes.js");
+
+/** Build the routes with stubs and return a caller for GET /health. */
+function healthHandler({ authenticated = false, draining = false, clientCount = 3 } = {}) {
+  const passthrough = (handler) => handler;
+  const routes = createAppRoutes({
+    json: (res, status, data) => { res.status = status; res.body = data; },
+    isAuthenticated: () => authenticated,
+    getDraining: () => draining,
+    getClientCount: () => clientCount,
+    APP_VERSION: "0.0.0-test",
+    auth: passthrough,
+    csrf: passthrough,
+  });
+  const route = routes.find(r => r.method === "GET" && r.path === "/health");
+  return () => {
+    const res = {};
+    route.handler({ headers: {} }, res);
+    return res;
+  };
+}
+
 describe("health endpoint", () => {
   describe("isPublicPath", () => {
     it("allows /health as a public path", () => {
@@ -16,4 +47,24 @@ describe("health endpoint", () => {
       assert.equal(isPublicPath("/healthz"), false);
     });
   });
+
+  describe("GET /health", () => {
+    it("includes the connected client count when authenticated", () => {
+      const res = healthHandler({ authenticated: true, clientCount: 3 })();
+      assert.equal(res.status, 200);
+      assert.equal(res.body.clients, 3);
+    });
+
+    it("omits the client count for unauthenticated requests", () => {
+      const res = healthHandler({ authenticated: false })();
+      assert.equal(res.status, 200);
+      assert.deepEqual(res.body, { status: "ok", version: "0.0.0-test" });
+    });
+
+    it("omits the client count while draining", () => {
+      const res = healthHandler({ authenticated: true, draining: true })();
+      assert.equal(res.status, 503);
+      assert.deepEqual(res.body, { status: "draining" });
+    });
+  });
 });
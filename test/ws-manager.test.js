@@ -161,6 +161,27 @@ describe("createWebSocketManager", () => {
     wsMgr.closeAllWebSockets();
   });
 
+  describe("heartbeat", () => {
+    it("terminates after the configured number of missed pongs", () => {
+      mock.timers.enable({ apis: ["setInterval"] });
+      try {
+        const mgr = createWebSocketManager({
+          bridge: createMockBridge(), sessionManager,
+          pingIntervalMs: 1000, maxMissedPongs: 3,
+        });
+        const ws = createMockWs();
+        mgr.handleConnection(ws);
+
+        mock.timers.tick(3000); // first ping, then two misses
+        assert.equal(ws._terminated, false);
+        mock.timers.tick(1000); // third miss
+        assert.equal(ws._terminated, true);
+      } finally {
+        mock.timers.reset();
+      }
+    });
+  });
+
   describe("sendToSession", () => {
     it("routes messages only to clients on the target session", () => {
       const ws1 = createMockWs();
@@ -351,6 +372,22 @@ describe("createWebSocketManager", () => {
       assert.equal(writeInputCalls[0].session, undefined);
     });
 
+    it("malformed JSON gets an error reply instead of being dropped", async () => {
+      const ws = createMockWs();
+      wsMgr.handleConnection(ws);
+
+      await ws._fireMessage(Buffer.from("{not json"));
+      await new Promise(r => setTimeout(r, 10));
+
+      const errorMsg = ws.sent.map(s => JSON.parse(s)).find(m => m.type === "error");
+      assert.ok(errorMsg, "should receive error reply");
+      assert.equal(errorMsg.message, "Malformed JSON");
+
+      // Never attached, so afterEach's closeAllWebSockets won't see it —
+      // close explicitly to clear the heartbeat interval.
+      ws.close();
+    });
+
     it("resize-sync excludes the active client", () => {
       const ws1 = createMockWs();
       const ws2 = createMockWs();